@@ -1,10 +1,15 @@
 //! Launch the Ethereum node.
 
-use futures::{future::Either, stream, stream_select, StreamExt};
+use futures::{
+    future::{BoxFuture, Either},
+    stream, stream_select, FutureExt, StreamExt,
+};
 use reth_beacon_consensus::{
     hooks::{EngineHooks, PruneHook, StaticFileHook},
-    BeaconConsensusEngineHandle,
+    BeaconConsensusEngineError, BeaconConsensusEngineEvent, BeaconConsensusEngineHandle,
+    BeaconEngineMessage,
 };
+use reth_chainspec::ChainSpec;
 use reth_ethereum_engine::service::EthService;
 use reth_ethereum_engine_primitives::EthEngineTypes;
 use reth_exex::ExExManagerHandle;
@@ -25,34 +30,121 @@ use reth_node_core::{
 use reth_node_events::{cl::ConsensusLayerHealthEvents, node};
 use reth_provider::providers::BlockchainProvider;
 use reth_rpc_engine_api::{capabilities::EngineCapabilities, EngineApi};
-use reth_rpc_types::engine::ClientVersionV1;
-use reth_tasks::TaskExecutor;
+use reth_rpc_types::engine::{ClientVersionV1, ForkchoiceState};
+use reth_tasks::{TaskExecutor, TaskSpawner};
 use reth_tokio_util::EventSender;
 use reth_tracing::tracing::{debug, info};
-use std::sync::mpsc::channel;
+use std::{
+    future::Future,
+    marker::PhantomData,
+    sync::{mpsc::channel, Arc},
+};
 use tokio::sync::{mpsc::unbounded_channel, oneshot};
 use tokio_stream::wrappers::UnboundedReceiverStream;
 
 /// The Ethereum node launcher.
+///
+/// Generic over the [`EngineServiceBuilder`] `ESB` that constructs the consensus engine service,
+/// defaulting to [`EthEngineServiceBuilder`]. Alternative engines supply their own builder to reuse
+/// the launch flow without copying `launch_node`.
 #[derive(Debug)]
-pub struct EthNodeLauncher {
+pub struct EthNodeLauncher<ESB = EthEngineServiceBuilder> {
     /// The task executor for the node.
     pub ctx: LaunchContext,
+    /// The engine service builder used to construct the consensus engine.
+    _engine_service_builder: PhantomData<ESB>,
 }
 
 impl EthNodeLauncher {
     /// Create a new instance of the ethereum node launcher.
     pub const fn new(task_executor: TaskExecutor, data_dir: ChainPath<DataDirPath>) -> Self {
-        Self { ctx: LaunchContext::new(task_executor, data_dir) }
+        Self {
+            ctx: LaunchContext::new(task_executor, data_dir),
+            _engine_service_builder: PhantomData,
+        }
+    }
+}
+
+impl<ESB> EthNodeLauncher<ESB> {
+    /// Create a new launcher that builds its consensus engine with the [`EngineServiceBuilder`]
+    /// `ESB`.
+    pub const fn with_engine_service_builder(
+        task_executor: TaskExecutor,
+        data_dir: ChainPath<DataDirPath>,
+    ) -> Self {
+        Self {
+            ctx: LaunchContext::new(task_executor, data_dir),
+            _engine_service_builder: PhantomData,
+        }
     }
 }
 
-impl<T, CB, AO> LaunchNode<NodeBuilderWithComponents<T, CB, AO>> for EthNodeLauncher
+/// Builds the consensus engine service that [`EthNodeLauncher`] drives to completion.
+///
+/// The launcher obtains its engine service through this trait, selected by the launcher's
+/// `ESB` type parameter, rather than naming a concrete service type. Alternative engines
+/// (Optimism-style or custom payload types) plug their own service into the launcher's
+/// pipeline/pruner/static-file/RPC setup by implementing this for their node types; Ethereum nodes
+/// use the impl below, backed by [`EthService`].
+pub trait EngineServiceBuilder<T: FullNodeTypes> {
+    /// The engine service future the launcher runs to completion.
+    type Service: Future<Output = Result<(), BeaconConsensusEngineError>> + Send + 'static;
+
+    /// Builds the engine service from the launcher-constructed pipeline, channels and event
+    /// sender.
+    #[allow(clippy::too_many_arguments)]
+    fn build_engine_service<Client, ToTree, FromTree, P>(
+        chain_spec: Arc<ChainSpec>,
+        client: Client,
+        to_tree: ToTree,
+        from_tree: FromTree,
+        incoming: UnboundedReceiverStream<BeaconEngineMessage<T::Engine>>,
+        pipeline: P,
+        task_spawner: Box<dyn TaskSpawner>,
+        event_sender: EventSender<BeaconConsensusEngineEvent>,
+    ) -> Self::Service;
+}
+
+/// [`EngineServiceBuilder`] for Ethereum nodes, wiring the [`EthService`] engine.
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct EthEngineServiceBuilder;
+
+impl<T> EngineServiceBuilder<T> for EthEngineServiceBuilder
 where
-    T: FullNodeTypes<
-        Provider = BlockchainProvider<<T as FullNodeTypes>::DB>,
-        Engine = EthEngineTypes,
-    >,
+    T: FullNodeTypes<Engine = EthEngineTypes>,
+{
+    type Service = BoxFuture<'static, Result<(), BeaconConsensusEngineError>>;
+
+    fn build_engine_service<Client, ToTree, FromTree, P>(
+        chain_spec: Arc<ChainSpec>,
+        client: Client,
+        to_tree: ToTree,
+        from_tree: FromTree,
+        incoming: UnboundedReceiverStream<BeaconEngineMessage<T::Engine>>,
+        pipeline: P,
+        task_spawner: Box<dyn TaskSpawner>,
+        event_sender: EventSender<BeaconConsensusEngineEvent>,
+    ) -> Self::Service {
+        EthService::<T>::new(
+            chain_spec,
+            client,
+            to_tree,
+            from_tree,
+            incoming,
+            pipeline,
+            task_spawner,
+            event_sender,
+        )
+        .boxed()
+    }
+}
+
+impl<T, CB, AO, ESB> LaunchNode<NodeBuilderWithComponents<T, CB, AO>> for EthNodeLauncher<ESB>
+where
+    T: FullNodeTypes<Provider = BlockchainProvider<<T as FullNodeTypes>::DB>>,
+    // The engine service is built by the launcher's `ESB` type parameter, not hardcoded.
+    ESB: EngineServiceBuilder<T>,
     CB: NodeComponentsBuilder<T>,
     AO: NodeAddOns<NodeAdapter<T, CB::Components>>,
     AO::EthApi:
@@ -64,7 +156,7 @@ where
         self,
         target: NodeBuilderWithComponents<T, CB, AO>,
     ) -> eyre::Result<Self::Node> {
-        let Self { ctx } = self;
+        let Self { ctx, .. } = self;
         let NodeBuilderWithComponents {
             adapter: NodeTypesAdapter { database },
             components_builder,
@@ -147,8 +239,7 @@ where
 
         let pipeline_events = pipeline.events();
 
-        // TODO: support --debug.tip
-        let _initial_target = ctx.node_config().debug.tip;
+        let initial_target = ctx.node_config().debug.tip;
 
         let mut pruner_builder = ctx.pruner_builder();
         if let Some(exex_manager_handle) = &exex_manager_handle {
@@ -164,8 +255,14 @@ where
         let (to_tree_tx, _to_tree_rx) = channel();
         let (_from_tree_tx, from_tree_rx) = unbounded_channel();
 
-        // Configure the consensus engine
-        let eth_service = EthService::new(
+        // The event sender the engine publishes lifecycle events on. A clone is handed to the
+        // engine service so it actually emits events; we listen on another and share the last with
+        // the consensus engine handle.
+        let event_sender = EventSender::default();
+
+        // Configure the consensus engine. The service type is resolved from the launcher's `ESB`
+        // via `EngineServiceBuilder` rather than constructed directly here.
+        let eth_service = <ESB as EngineServiceBuilder<T>>::build_engine_service(
             ctx.chain_spec(),
             network_client.clone(),
             // to tree
@@ -175,9 +272,29 @@ where
             UnboundedReceiverStream::new(consensus_engine_rx),
             pipeline,
             Box::new(ctx.task_executor().clone()),
+            event_sender.clone(),
         );
 
-        let event_sender = EventSender::default();
+        // If `--debug.tip` is set, drive the engine toward that fixed block hash by sending a
+        // one-shot forkchoice update instead of waiting for a consensus client. The node will
+        // sync to the pinned tip and then idle.
+        if let Some(tip) = initial_target {
+            debug!(target: "reth::cli", %tip, "Sending initial forkchoice update to pinned tip");
+            let (tx, _rx) = oneshot::channel();
+            let _ = consensus_engine_tx.send(BeaconEngineMessage::ForkchoiceUpdated {
+                state: ForkchoiceState {
+                    head_block_hash: tip,
+                    safe_block_hash: tip,
+                    finalized_block_hash: tip,
+                },
+                payload_attrs: None,
+                tx,
+            });
+        }
+
+        // Subscribe to engine lifecycle events (new payloads, applied forkchoice updates, sync
+        // target reached, backfill transitions) so they surface in the unified node event stream.
+        let engine_events = event_sender.new_listener().map(Into::into);
 
         let beacon_engine_handle =
             BeaconConsensusEngineHandle::new(consensus_engine_tx, event_sender);
@@ -186,7 +303,7 @@ where
 
         let events = stream_select!(
             ctx.components().network().event_listener().map(Into::into),
-            // TODO get engine events
+            engine_events,
             pipeline_events.map(Into::into),
             if ctx.node_config().debug.tip.is_none() && !ctx.is_dev() {
                 Either::Left(
@@ -215,6 +332,10 @@ where
             version: CARGO_PKG_VERSION.to_string(),
             commit: VERGEN_GIT_SHA.to_string(),
         };
+        // Translate the add-ons' optional capability override (plain method names) into the rpc
+        // layer's `EngineCapabilities`, falling back to the default advertised set.
+        let engine_capabilities = AO::engine_capabilities(ctx.node_adapter())
+            .map_or_else(EngineCapabilities::default, EngineCapabilities::new);
         let engine_api = EngineApi::new(
             ctx.blockchain_db().clone(),
             ctx.chain_spec(),
@@ -222,13 +343,24 @@ where
             ctx.components().payload_builder().clone().into(),
             Box::new(ctx.task_executor().clone()),
             client,
-            EngineCapabilities::default(),
+            engine_capabilities,
         );
         info!(target: "reth::cli", "Engine API handler initialized");
 
         // extract the jwt secret from the args if possible
         let jwt_secret = ctx.auth_jwt_secret()?;
 
+        // Install any auxiliary RPC namespaces contributed by the add-ons. Registering a hook here
+        // merges them into the transport modules *before* the servers are built and started, so
+        // the extra namespaces are actually served to clients.
+        let mut rpc = rpc;
+        if let Some(modules) = AO::extend_rpc_modules(ctx.node_adapter())? {
+            rpc.extend_rpc_modules(move |ctx| {
+                ctx.modules.merge_configured(modules.clone())?;
+                Ok(())
+            });
+        }
+
         // Start RPC servers
         let (rpc_server_handles, rpc_registry) = launch_rpc_servers(
             ctx.node_adapter().clone(),
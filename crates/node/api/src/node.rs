@@ -2,6 +2,7 @@
 
 use std::marker::PhantomData;
 
+use jsonrpsee::RpcModule;
 use reth_db_api::{
     database::Database,
     database_metrics::{DatabaseMetadata, DatabaseMetrics},
@@ -153,6 +154,29 @@ pub trait NodeAddOns<N: FullNodeComponents>: Send + Sync + Unpin + Clone + 'stat
     /// The core `eth` namespace API type to install on the RPC server (see
     /// `reth_rpc_eth_api::EthApiServer`).
     type EthApi: Send + Clone;
+
+    /// Returns an override of the `engine_*` capabilities advertised by the node's engine API, as
+    /// the set of method names to advertise.
+    ///
+    /// Override this to add or restrict the advertised method set, e.g. for forks and test
+    /// networks that implement custom engine methods or do not implement some of the defaults. The
+    /// node adapter is provided so the set can depend on node state. Returning `None` keeps the
+    /// default capability set. The capability type itself lives in the rpc layer, so this is kept
+    /// as plain method names to avoid inverting the crate dependency graph.
+    fn engine_capabilities(_node: &N) -> Option<Vec<String>> {
+        None
+    }
+
+    /// Returns additional JSON-RPC modules to merge into the modules served on the node's
+    /// configured RPC transports.
+    ///
+    /// This is the hook for exposing custom namespaces (e.g. indexing or MEV endpoints) alongside
+    /// the built-in `eth`/`engine` namespaces without forking the launcher. The node adapter is
+    /// provided so implementors can wire handlers against the node's provider, pool and network.
+    /// The default installs no extra modules.
+    fn extend_rpc_modules(_node: &N) -> eyre::Result<Option<RpcModule<()>>> {
+        Ok(None)
+    }
 }
 
 impl<N: FullNodeComponents> NodeAddOns<N> for () {